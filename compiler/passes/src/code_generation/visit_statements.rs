@@ -31,10 +31,11 @@ use leo_ast::{
     Output,
     ReturnStatement,
     Statement,
+    Type,
 };
 
 use itertools::Itertools;
-use std::fmt::Write as _;
+use std::{collections::HashSet, fmt::Write as _};
 
 impl<'a> CodeGenerator<'a> {
     fn visit_statement(&mut self, input: &'a Statement) -> String {
@@ -95,10 +96,23 @@ impl<'a> CodeGenerator<'a> {
                     self.current_function.unwrap().output.iter()
                 };
                 // If the operand string is empty, initialize an empty vector.
-                let operand_strings = match operand.is_empty() {
+                let mut operand_strings = match operand.is_empty() {
                     true => vec![],
-                    false => operand.split(' ').collect_vec(),
+                    false => operand.split(' ').map(String::from).collect_vec(),
                 };
+                // Note that snarkVM rejects duplicate output statements, so repeated operands are copied into new registers.
+                let mut seen_operands = HashSet::new();
+                for (operand, output) in operand_strings.iter_mut().zip_eq(output.clone()) {
+                    if seen_operands.insert(operand.clone()) {
+                        continue;
+                    }
+                    if let Output::Internal(output) = output {
+                        if let Some((register, instructions)) = self.copy_into_register(operand, &output.type_) {
+                            expression_instructions.push_str(&instructions);
+                            *operand = register;
+                        }
+                    }
+                }
                 let instructions = operand_strings
                     .iter()
                     .zip_eq(output)
@@ -188,6 +202,35 @@ impl<'a> CodeGenerator<'a> {
         instructions
     }
 
+    /// Copies `operand` into a new register, returning the register and the instruction that produces it.
+    /// Returns `None` if values of type `type_` cannot be reconstructed with a `cast` instruction.
+    fn copy_into_register(&mut self, operand: &str, type_: &Type) -> Option<(String, String)> {
+        let operands = match type_ {
+            Type::Address | Type::Boolean | Type::Field | Type::Group | Type::Integer(..) | Type::Scalar => {
+                operand.to_string()
+            }
+            Type::Array(array_type) => (0..array_type.length()).map(|index| format!("{operand}[{index}u32]")).join(" "),
+            Type::Identifier(identifier) => match self.symbol_table.lookup_struct(identifier.name) {
+                // Note that records are not copied, since this would duplicate the record.
+                Some(struct_) if !struct_.is_record => {
+                    struct_.members.iter().map(|member| format!("{operand}.{}", member.identifier)).join(" ")
+                }
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        // Construct the destination register.
+        let destination_register = format!("r{}", self.next_register);
+        // Increment the register counter.
+        self.next_register += 1;
+
+        let cast_instruction =
+            format!("    cast {operands} into {destination_register} as {};\n", Self::visit_type(type_));
+
+        Some((destination_register, cast_instruction))
+    }
+
     fn visit_definition(&mut self, _input: &'a DefinitionStatement) -> String {
         // TODO: If SSA is made optional, then conditionally enable codegen for DefinitionStatement
         // let (operand, expression_instructions) = self.visit_expression(&input.value);
@@ -30,6 +30,24 @@ impl ExpressionReconstructor for Unroller<'_> {
             self.emit_err(LoopUnrollerError::variable_array_access(input.span));
         }
 
+        // If the index is an integer literal, check that it is within the bounds of the array.
+        if let (Expression::Literal(literal @ Literal::Integer(..)), Some(Type::Array(array_type))) =
+            (&index, self.type_table.get(&input.array.id()))
+        {
+            // Note that type checking guarantees that the literal is a valid value of its integer type.
+            if let Ok(value) = Value::try_from(literal) {
+                // Negative values and values that do not fit in a `usize` are out of bounds.
+                match u128::try_from(&value).ok().and_then(|index| usize::try_from(index).ok()) {
+                    Some(index) if index < array_type.length() => {}
+                    _ => self.emit_err(LoopUnrollerError::array_index_out_of_bounds(
+                        value,
+                        array_type.length(),
+                        input.span,
+                    )),
+                }
+            }
+        }
+
         (
             Expression::Access(AccessExpression::Array(ArrayAccess {
                 array: Box::new(self.reconstruct_expression(*input.array).0),
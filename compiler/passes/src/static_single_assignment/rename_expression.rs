@@ -22,11 +22,13 @@ use leo_ast::{
     ArrayExpression,
     AssociatedFunction,
     BinaryExpression,
+    BinaryOperation,
     CallExpression,
     CastExpression,
     Expression,
     ExpressionConsumer,
     Identifier,
+    IntegerType,
     Literal,
     MemberAccess,
     Statement,
@@ -38,11 +40,35 @@ use leo_ast::{
     TupleExpression,
     UnaryExpression,
     UnitExpression,
+    Value,
 };
 use leo_span::{sym, Symbol};
 
 use indexmap::IndexMap;
 
+/// Returns the result of an ordering comparison between two integer literals of the same type.
+/// Returns `None` if `op` is not an ordering comparison or if either literal cannot be parsed.
+fn fold_integer_comparison(
+    op: BinaryOperation,
+    integer_type: &IntegerType,
+    left: &Literal,
+    right: &Literal,
+) -> Option<bool> {
+    let (left, right) = (Value::try_from(left).ok()?, Value::try_from(right).ok()?);
+    // Note that type checking guarantees that both operands have the same integer type.
+    let ordering = match integer_type.is_signed() {
+        true => i128::try_from(&left).ok()?.cmp(&i128::try_from(&right).ok()?),
+        false => u128::try_from(&left).ok()?.cmp(&u128::try_from(&right).ok()?),
+    };
+    match op {
+        BinaryOperation::Lt => Some(ordering.is_lt()),
+        BinaryOperation::Lte => Some(ordering.is_le()),
+        BinaryOperation::Gt => Some(ordering.is_gt()),
+        BinaryOperation::Gte => Some(ordering.is_ge()),
+        _ => None,
+    }
+}
+
 impl ExpressionConsumer for StaticSingleAssigner<'_> {
     type Output = (Expression, Vec<Statement>);
 
@@ -150,7 +176,28 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
     }
 
     /// Consumes a binary expression, accumulating any statements that are generated.
+    /// If both operands of a logical AND or OR are boolean literals, or both operands of an ordering comparison are
+    /// integer literals, the expression is folded into a single boolean literal.
     fn consume_binary(&mut self, input: BinaryExpression) -> Self::Output {
+        // Fold logical operations and comparisons over constant operands.
+        let value = match (&*input.left, &*input.right) {
+            (Expression::Literal(Literal::Boolean(left, ..)), Expression::Literal(Literal::Boolean(right, ..))) => {
+                match input.op {
+                    BinaryOperation::And => Some(*left && *right),
+                    BinaryOperation::Or => Some(*left || *right),
+                    _ => None,
+                }
+            }
+            (
+                Expression::Literal(left @ Literal::Integer(integer_type, ..)),
+                Expression::Literal(right @ Literal::Integer(..)),
+            ) => fold_integer_comparison(input.op, integer_type, left, right),
+            _ => None,
+        };
+        if let Some(value) = value {
+            return self.consume_literal(Literal::Boolean(value, input.span, input.id));
+        }
+
         // Reconstruct the lhs of the binary expression.
         let (left_expression, mut statements) = self.consume_expression(*input.left);
         // Reconstruct the rhs of the binary expression.
@@ -318,7 +365,17 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
     }
 
     /// Consumes a ternary expression, accumulating any statements that are generated.
+    /// If the condition is a boolean literal, only the taken branch is consumed and the other is discarded.
     fn consume_ternary(&mut self, input: TernaryExpression) -> Self::Output {
+        // If the condition is a constant, consume only the taken branch.
+        // Note that the loop unroller has already substituted constant variables with their values.
+        if let Expression::Literal(Literal::Boolean(condition, ..)) = *input.condition {
+            return match condition {
+                true => self.consume_expression(*input.if_true),
+                false => self.consume_expression(*input.if_false),
+            };
+        }
+
         // Reconstruct the condition of the ternary expression.
         let (cond_expr, mut statements) = self.consume_expression(*input.condition);
         // Reconstruct the if-true case of the ternary expression.
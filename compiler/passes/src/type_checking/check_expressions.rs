@@ -22,7 +22,7 @@ use leo_span::{sym, Span};
 
 use itertools::Itertools;
 use snarkvm::console::network::{Network, Testnet3};
-use std::str::FromStr;
+use std::{collections::HashSet, str::FromStr};
 
 fn return_incorrect_type(t1: Option<Type>, t2: Option<Type>, expected: &Option<Type>) -> Option<Type> {
     match (t1, t2) {
@@ -653,6 +653,18 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 ));
             }
 
+            // Check that each member is initialized at most once.
+            let mut initialized = HashSet::new();
+            input.members.iter().for_each(|member| {
+                if !initialized.insert(member.identifier.name) {
+                    self.emit_err(TypeCheckerError::duplicate_struct_member_initializer(
+                        struct_.identifier,
+                        member.identifier,
+                        member.span,
+                    ));
+                }
+            });
+
             // Check struct member types.
             struct_.members.iter().for_each(|Member { identifier, type_, .. }| {
                 // Lookup struct variable name.
@@ -672,6 +684,17 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 };
             });
 
+            // Check that each initialized member is defined by the struct.
+            input.members.iter().for_each(|member| {
+                if !struct_.members.iter().any(|Member { identifier, .. }| identifier.name == member.identifier.name) {
+                    self.emit_err(TypeCheckerError::invalid_struct_variable(
+                        member.identifier,
+                        struct_.identifier,
+                        member.span,
+                    ));
+                }
+            });
+
             Some(ret)
         } else {
             self.emit_err(TypeCheckerError::unknown_sym("struct", input.name.name, input.name.span()));
@@ -712,4 +712,11 @@ create_messages!(
         msg: format!("An array cannot have a record as an element type"),
         help: None,
     }
+
+    @formatted
+    duplicate_struct_member_initializer {
+        args: (struct_: impl Display, member: impl Display),
+        msg: format!("Struct initialization expression for `{struct_}` initializes member `{member}` more than once."),
+        help: None,
+    }
 );